@@ -1,8 +1,10 @@
 use std::collections::BTreeMap;
 use std::collections::Bound::*;
-use std::marker::PhantomData;
 use std::ops::{Add, Mul};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 type Position = i64;
 type Time = f64;
 type Keyframe<'a, T> = (&'a Position, &'a T);
@@ -38,94 +40,390 @@ impl<'a> Mul<f64> for &'a Vector {
     }
 }
 
+impl Mul<f64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, other: f64) -> Vector {
+        &self * other
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    x: f64,
+    y: f64,
+    z: f64,
+    w: f64
+}
+
+impl Quaternion {
+    fn dot(&self, other: &Quaternion) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    fn scale(&self, s: f64) -> Quaternion {
+        Quaternion {
+            x: self.x * s,
+            y: self.y * s,
+            z: self.z * s,
+            w: self.w * s
+        }
+    }
+
+    fn added(&self, other: &Quaternion) -> Quaternion {
+        Quaternion {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w
+        }
+    }
+
+    fn normalized(&self) -> Quaternion {
+        self.scale(1.0 / self.dot(self).sqrt())
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, rhs: Quaternion) -> Quaternion {
+        self.added(&rhs)
+    }
+}
+
+impl<'a> Mul<f64> for &'a Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, other: f64) -> Quaternion {
+        self.scale(other)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Interpolation {
+    Hold,
+    Linear,
+    CatmullRom,
+    Step(f64),
+}
+
+impl Default for Interpolation {
+    fn default() -> Interpolation {
+        Interpolation::Linear
+    }
+}
+
 pub trait Curve<T> {
-    fn set(&mut self, key: Position, value: T);
+    fn set(&mut self, key: Position, value: T, mode: Interpolation);
+
+    fn set_default(&mut self, key: Position, value: T) {
+        self.set(key, value, Interpolation::default());
+    }
+
     fn value_at(&self, wanted_key: &Position) -> T;
+    fn sample(&self, key: &Position) -> Option<T>;
+    fn clamped_sample(&self, key: &Position) -> T;
+    fn domain(&self) -> Option<(Position, Position)>;
+    fn sample_iter<'b>(&'b self, start: Position, end: Position, step: Position) -> Box<Iterator<Item = (Position, T)> + 'b>;
+    fn resample(&self, step: Position) -> Box<Curve<T>>;
 }
 
 pub trait Interpolatable<'a, T> {
     fn interpolate(pre: &Keyframe<T>, post: &Keyframe<T>, time: Time) -> T;
 }
 
-impl<'a> Interpolatable<'a, f64> for f64 {
-    fn interpolate(pre: &Keyframe<f64>, post: &Keyframe<f64>, time: Time) -> f64 {
+pub trait VectorSpace: Copy + Add<Output = Self> + Mul<f64, Output = Self> {}
+
+impl<T: Copy + Add<Output = T> + Mul<f64, Output = T>> VectorSpace for T {}
+
+fn lerp<T: VectorSpace>(a: T, b: T, alpha: f64) -> T {
+    a * (1.0 - alpha) + b * alpha
+}
+
+// Covers f64, Vector, and Pair (any VectorSpace) with one linear body.
+// Quaternion stays out of VectorSpace (no owned `Mul<f64>`) because its
+// interpolation is slerp, not lerp, and would otherwise conflict with this impl.
+impl<'a, T: VectorSpace> Interpolatable<'a, T> for T {
+    fn interpolate(pre: &Keyframe<T>, post: &Keyframe<T>, time: Time) -> T {
         if pre.0 == post.0 {
             return *pre.1;
         }
 
         let alpha = (time - (*pre.0 as Time)) / ((post.0 - pre.0) as Time);
-        let p1 = pre.1 * (1.0 - alpha);
-        let p2 = post.1 * alpha;
-        return p1 + p2;
+        return lerp(*pre.1, *post.1, alpha);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pair<A, B>(pub A, pub B);
+
+impl<A: Add<Output = A>, B: Add<Output = B>> Add for Pair<A, B> {
+    type Output = Pair<A, B>;
+
+    fn add(self, rhs: Pair<A, B>) -> Pair<A, B> {
+        Pair(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl<'a, A: Copy + Mul<f64, Output = A>, B: Copy + Mul<f64, Output = B>> Mul<f64> for &'a Pair<A, B> {
+    type Output = Pair<A, B>;
+
+    fn mul(self, other: f64) -> Pair<A, B> {
+        Pair(self.0 * other, self.1 * other)
+    }
+}
+
+impl<A: Copy + Mul<f64, Output = A>, B: Copy + Mul<f64, Output = B>> Mul<f64> for Pair<A, B> {
+    type Output = Pair<A, B>;
+
+    fn mul(self, other: f64) -> Pair<A, B> {
+        &self * other
     }
 }
 
-impl<'a> Interpolatable<'a, Vector> for Vector {
-    fn interpolate(pre: &Keyframe<Vector>, post: &Keyframe<Vector>, time: Time) -> Vector {
+impl<'a> Interpolatable<'a, Quaternion> for Quaternion {
+    fn interpolate(pre: &Keyframe<Quaternion>, post: &Keyframe<Quaternion>, time: Time) -> Quaternion {
         if pre.0 == post.0 {
             return *pre.1;
         }
 
         let alpha = (time - (*pre.0 as Time)) / ((post.0 - pre.0) as Time);
-        let p1 = pre.1 * (1.0 - alpha);
-        let p2 = post.1 * alpha;
-        return p1 + p2;
+
+        let q0 = pre.1.normalized();
+        let mut q1 = post.1.normalized();
+
+        let mut d = q0.dot(&q1);
+        if d < 0.0 {
+            q1 = q1.scale(-1.0);
+            d = -d;
+        }
+
+        if d > 0.9995 {
+            return q0.scale(1.0 - alpha).added(&q1.scale(alpha)).normalized();
+        }
+
+        let theta = d.acos();
+        return q0.scale(((1.0 - alpha) * theta).sin())
+            .added(&q1.scale((alpha * theta).sin()))
+            .scale(1.0 / theta.sin());
     }
 }
 
 pub trait Interpolator {
-    fn get<'a, T: Interpolatable<'a, T>>(pre: &Keyframe<T>, post: &Keyframe<T>, time: Time) -> T;
+    fn get<'a, T>(p0: &Option<Keyframe<T>>, p1: &Keyframe<T>, p2: &Keyframe<T>, p3: &Option<Keyframe<T>>, time: Time) -> T
+        where T: Interpolatable<'a, T> + Clone + Add<Output = T>, for<'b> &'b T: Mul<f64, Output = T>;
 }
 
 pub struct LinearInterpolator;
 pub struct HoldInterpolator;
+pub struct CatmullRomInterpolator;
 
 impl Interpolator for LinearInterpolator {
-    fn get<'a, T: Interpolatable<'a, T>>(pre: &Keyframe<T>, post: &Keyframe<T>, time: Time) -> T {
-        return T::interpolate(pre, post, time);
-
+    fn get<'a, T>(_: &Option<Keyframe<T>>, p1: &Keyframe<T>, p2: &Keyframe<T>, _: &Option<Keyframe<T>>, time: Time) -> T
+        where T: Interpolatable<'a, T> + Clone + Add<Output = T>, for<'b> &'b T: Mul<f64, Output = T>
+    {
+        return T::interpolate(p1, p2, time);
     }
 }
 
 impl Interpolator for HoldInterpolator {
-    fn get<'a, T: Interpolatable<'a, T>>(pre: &Keyframe<T>, _: &Keyframe<T>, _: Time) -> T {
-        return T::interpolate(pre, pre, *pre.0 as Time);
+    fn get<'a, T>(_: &Option<Keyframe<T>>, p1: &Keyframe<T>, _: &Keyframe<T>, _: &Option<Keyframe<T>>, _: Time) -> T
+        where T: Interpolatable<'a, T> + Clone + Add<Output = T>, for<'b> &'b T: Mul<f64, Output = T>
+    {
+        return T::interpolate(p1, p1, *p1.0 as Time);
+    }
+}
+
+impl Interpolator for CatmullRomInterpolator {
+    fn get<'a, T>(p0: &Option<Keyframe<T>>, p1: &Keyframe<T>, p2: &Keyframe<T>, p3: &Option<Keyframe<T>>, time: Time) -> T
+        where T: Interpolatable<'a, T> + Clone + Add<Output = T>, for<'b> &'b T: Mul<f64, Output = T>
+    {
+        if p1.0 == p2.0 {
+            return (*p1.1).clone();
+        }
+
+        let p0 = p0.unwrap_or(*p1);
+        let p3 = p3.unwrap_or(*p2);
+
+        let t = (time - (*p1.0 as Time)) / ((p2.0 - p1.0) as Time);
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let a = p1.1 * 2.0;
+        let b = p0.1 * -1.0 + p2.1 * 1.0;
+        let c = p0.1 * 2.0 + p1.1 * -5.0 + p2.1 * 4.0 + p3.1 * -1.0;
+        let d = p0.1 * -1.0 + p1.1 * 3.0 + p2.1 * -3.0 + p3.1 * 1.0;
+
+        let sum = a + &b * t + &c * t2 + &d * t3;
+        return &sum * 0.5;
     }
 }
 
-pub struct BTreeCurve<T, IP: Interpolator> {
-    points: BTreeMap<Position, T>,
-    interpolator: PhantomData<IP>
+pub struct BTreeCurve<T> {
+    points: BTreeMap<Position, (T, Interpolation)>
 }
 
-impl <'a, T, IP> BTreeCurve<T, IP> where T: Clone + Interpolatable<'a, T> + 'static, IP: Interpolator + 'static {
+impl <'a, T> BTreeCurve<T> where T: Clone + Interpolatable<'a, T> + Add<Output = T> + 'static, for<'b> &'b T: Mul<f64, Output = T> {
     pub fn new() -> Box<Curve<T>> {
-        return Box::new(BTreeCurve::<T, IP> {
-            points: BTreeMap::new(),
-            interpolator: PhantomData
+        return Box::new(BTreeCurve::<T> {
+            points: BTreeMap::new()
         });
     }
+
+    // Concrete (non-boxed) so callers who need Serialize/Deserialize, which
+    // aren't part of the Curve trait, can name BTreeCurve<T> directly.
+    pub fn new_concrete() -> BTreeCurve<T> {
+        BTreeCurve::<T> {
+            points: BTreeMap::new()
+        }
+    }
 }
 
-impl <'a, T, IP> Curve<T> for BTreeCurve<T, IP> where T: Clone + Interpolatable<'a, T>, IP: Interpolator {
-    fn set(&mut self, key: Position, value: T) {
-        self.points.insert(key, value);
+impl <'a, T> Curve<T> for BTreeCurve<T> where T: Clone + Interpolatable<'a, T> + Add<Output = T> + 'static, for<'b> &'b T: Mul<f64, Output = T> {
+    fn set(&mut self, key: Position, value: T, mode: Interpolation) {
+        self.points.insert(key, (value, mode));
     }
 
     fn value_at(&self, wanted_key: &Position) -> T {
-        let mut post_range = self.points.range((Included(wanted_key), Unbounded));
-        if let Some(post) = post_range.next() {
-            if wanted_key == post.0 {
-                return (*post.1).clone();
+        match self.sample(wanted_key) {
+            Some(value) => value,
+            None => panic!("too far"),
+        }
+    }
+
+    fn sample(&self, key: &Position) -> Option<T> {
+        let mut post_range = self.points.range((Included(key), Unbounded));
+        let (post_key, (post_value, _)) = post_range.next()?;
+
+        if key == post_key {
+            return Some(post_value.clone());
+        }
+
+        let mut pre_range = self.points.range((Unbounded, Excluded(key)));
+        let (pre_key, (pre_value, pre_mode)) = pre_range.next_back()?;
+
+        let p1 = (pre_key, pre_value);
+        let p2 = (post_key, post_value);
+        let p0 = pre_range.next_back().map(|(k, (v, _))| (k, v));
+        let p3 = post_range.next().map(|(k, (v, _))| (k, v));
+
+        Some(match pre_mode {
+            Interpolation::Hold => HoldInterpolator::get(&p0, &p1, &p2, &p3, *key as Time),
+            Interpolation::Linear => LinearInterpolator::get(&p0, &p1, &p2, &p3, *key as Time),
+            Interpolation::CatmullRom => CatmullRomInterpolator::get(&p0, &p1, &p2, &p3, *key as Time),
+            Interpolation::Step(threshold) => {
+                let alpha = (*key as Time - (*p1.0 as Time)) / ((p2.0 - p1.0) as Time);
+                if alpha < *threshold {
+                    p1.1.clone()
+                } else {
+                    p2.1.clone()
+                }
+            }
+        })
+    }
+
+    fn clamped_sample(&self, key: &Position) -> T {
+        if let Some(value) = self.sample(key) {
+            return value;
+        }
+
+        match (self.points.iter().next(), self.points.iter().next_back()) {
+            (Some((first_key, (first_value, _))), Some((_, (last_value, _)))) => {
+                if key < first_key {
+                    first_value.clone()
+                } else {
+                    last_value.clone()
+                }
+            }
+            _ => panic!("cannot clamp-sample an empty curve"),
+        }
+    }
+
+    fn domain(&self) -> Option<(Position, Position)> {
+        match (self.points.keys().next(), self.points.keys().next_back()) {
+            (Some(first), Some(last)) => Some((*first, *last)),
+            _ => None,
+        }
+    }
+
+    fn sample_iter<'b>(&'b self, start: Position, end: Position, step: Position) -> Box<Iterator<Item = (Position, T)> + 'b> {
+        let mut key = start;
+        let can_sample = !self.points.is_empty() && step > 0;
+        Box::new(std::iter::from_fn(move || {
+            if !can_sample || key > end {
+                return None;
             }
 
-            let mut pre_range = self.points.range((Unbounded, Excluded(wanted_key)));
-            let pre = pre_range.next_back().unwrap();
+            let value = self.clamped_sample(&key);
+            let result = (key, value);
+            key += step;
+            Some(result)
+        }))
+    }
+
+    fn resample(&self, step: Position) -> Box<Curve<T>> {
+        let mut result = BTreeCurve::<T>::new();
+
+        if let Some((start, end)) = self.domain() {
+            let mut last_key = None;
+            for (key, value) in self.sample_iter(start, end, step) {
+                result.set(key, value, Interpolation::Linear);
+                last_key = Some(key);
+            }
 
-            return IP::get(&pre, &post, *wanted_key as Time);
+            // The fixed step may not divide (end - start) evenly; always keep
+            // the true domain endpoint so a dense export doesn't truncate early.
+            if step > 0 && last_key != Some(end) {
+                result.set(end, self.clamped_sample(&end), Interpolation::Linear);
+            }
         }
 
-        panic!("too far");
+        result
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct KeyframeEntry<T> {
+    t: Position,
+    interpolation: Interpolation,
+    value: T,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize + Clone> Serialize for BTreeCurve<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.points.len()))?;
+        for (key, (value, mode)) in &self.points {
+            seq.serialize_element(&KeyframeEntry {
+                t: *key,
+                interpolation: *mode,
+                value: value.clone(),
+            })?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for BTreeCurve<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        let entries = Vec::<KeyframeEntry<T>>::deserialize(deserializer)?;
+        let mut points = BTreeMap::new();
+        for entry in entries {
+            points.insert(entry.t, (entry.value, entry.interpolation));
+        }
+
+        Ok(BTreeCurve { points })
     }
 }
 
@@ -133,12 +431,21 @@ impl <'a, T, IP> Curve<T> for BTreeCurve<T, IP> where T: Clone + Interpolatable<
 mod tests {
     use super::*;
 
+    #[test]
+    fn set_default_falls_back_to_the_default_interpolation_mode() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set_default(1, 100.0);
+        c.set_default(3, 300.0);
+
+        assert_eq!(c.value_at(&2), 200.0);
+    }
+
     #[test]
     fn linear_interpolation_works() {
-        let mut c = BTreeCurve::<f64, LinearInterpolator>::new();
-        c.set(1, 100.0);
-        c.set(3, 300.0);
-        c.set(6, 600.0);
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(1, 100.0, Interpolation::Linear);
+        c.set(3, 300.0, Interpolation::Linear);
+        c.set(6, 600.0, Interpolation::Linear);
         assert_eq!(c.value_at(&1), 100.0);
         assert_eq!(c.value_at(&3), 300.0);
         assert_eq!(c.value_at(&6), 600.0);
@@ -150,10 +457,10 @@ mod tests {
 
     #[test]
     fn hold_interpolation_works() {
-        let mut c = BTreeCurve::<f64, HoldInterpolator>::new();
-        c.set(1, 100.0);
-        c.set(3, 300.0);
-        c.set(6, 600.0);
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(1, 100.0, Interpolation::Hold);
+        c.set(3, 300.0, Interpolation::Hold);
+        c.set(6, 600.0, Interpolation::Hold);
         assert_eq!(c.value_at(&1), 100.0);
         assert_eq!(c.value_at(&3), 300.0);
         assert_eq!(c.value_at(&6), 600.0);
@@ -163,12 +470,25 @@ mod tests {
         assert_eq!(c.value_at(&5), 300.0);
     }
 
+    #[test]
+    fn step_interpolation_switches_at_the_threshold() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(0, 100.0, Interpolation::Step(0.5));
+        c.set(10, 200.0, Interpolation::Step(0.5));
+
+        assert_eq!(c.value_at(&0), 100.0);
+        assert_eq!(c.value_at(&4), 100.0);
+        assert_eq!(c.value_at(&5), 200.0);
+        assert_eq!(c.value_at(&9), 200.0);
+        assert_eq!(c.value_at(&10), 200.0);
+    }
+
     #[test]
     fn linear_interpolation_works_for_vectors() {
-        let mut c = BTreeCurve::<Vector, LinearInterpolator>::new();
-        c.set(1, Vector { x: 100.0, y: 1000.0, z: 10000.0 });
-        c.set(3, Vector { x: 300.0, y: 3000.0, z: 30000.0 });
-        c.set(6, Vector { x: 600.0, y: 6000.0, z: 60000.0 });
+        let mut c = BTreeCurve::<Vector>::new();
+        c.set(1, Vector { x: 100.0, y: 1000.0, z: 10000.0 }, Interpolation::Linear);
+        c.set(3, Vector { x: 300.0, y: 3000.0, z: 30000.0 }, Interpolation::Linear);
+        c.set(6, Vector { x: 600.0, y: 6000.0, z: 60000.0 }, Interpolation::Linear);
         assert_eq!(c.value_at(&1), Vector { x: 100.0, y: 1000.0, z: 10000.0 });
         assert_eq!(c.value_at(&3), Vector { x: 300.0, y: 3000.0, z: 30000.0 });
         assert_eq!(c.value_at(&6), Vector { x: 600.0, y: 6000.0, z: 60000.0 });
@@ -178,4 +498,215 @@ mod tests {
         assert_eq!(c.value_at(&5), Vector { x: 500.0, y: 5000.0, z: 50000.0 });
     }
 
+    #[test]
+    fn mixed_interpolation_modes_per_keyframe() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(1, 100.0, Interpolation::Hold);
+        c.set(3, 300.0, Interpolation::Linear);
+        c.set(6, 600.0, Interpolation::Linear);
+
+        assert_eq!(c.value_at(&2), 100.0);
+        assert_eq!(c.value_at(&4), 400.0);
+    }
+
+    #[test]
+    fn catmull_rom_interpolation_passes_through_keyframes() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(0, 0.0, Interpolation::CatmullRom);
+        c.set(10, 1.0, Interpolation::CatmullRom);
+        c.set(20, 2.0, Interpolation::CatmullRom);
+        c.set(30, 3.0, Interpolation::CatmullRom);
+        assert_eq!(c.value_at(&0), 0.0);
+        assert_eq!(c.value_at(&10), 1.0);
+        assert_eq!(c.value_at(&20), 2.0);
+        assert_eq!(c.value_at(&30), 3.0);
+    }
+
+    #[test]
+    fn catmull_rom_interpolation_matches_linear_for_collinear_keyframes() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(0, 0.0, Interpolation::CatmullRom);
+        c.set(10, 1.0, Interpolation::CatmullRom);
+        c.set(20, 2.0, Interpolation::CatmullRom);
+        c.set(30, 3.0, Interpolation::CatmullRom);
+
+        assert_eq!(c.value_at(&15), 1.5);
+    }
+
+    #[test]
+    fn sample_returns_none_outside_the_domain() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(1, 100.0, Interpolation::Linear);
+        c.set(6, 600.0, Interpolation::Linear);
+
+        assert_eq!(c.sample(&0), None);
+        assert_eq!(c.sample(&7), None);
+        assert_eq!(c.sample(&3), Some(300.0));
+    }
+
+    #[test]
+    fn clamped_sample_clamps_to_the_nearest_keyframe() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(1, 100.0, Interpolation::Linear);
+        c.set(6, 600.0, Interpolation::Linear);
+
+        assert_eq!(c.clamped_sample(&0), 100.0);
+        assert_eq!(c.clamped_sample(&7), 600.0);
+        assert_eq!(c.clamped_sample(&3), 300.0);
+    }
+
+    #[test]
+    fn clamped_sample_works_with_a_single_keyframe() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(5, 42.0, Interpolation::Linear);
+
+        assert_eq!(c.clamped_sample(&0), 42.0);
+        assert_eq!(c.clamped_sample(&5), 42.0);
+        assert_eq!(c.clamped_sample(&10), 42.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn curve_round_trips_through_serde_json() {
+        let mut points = BTreeMap::new();
+        points.insert(0, (0.0, Interpolation::Linear));
+        points.insert(1, (1.0, Interpolation::Hold));
+        points.insert(2, (2.0, Interpolation::CatmullRom));
+        let curve = BTreeCurve { points };
+
+        let json = serde_json::to_string(&curve).unwrap();
+        let restored: BTreeCurve<f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.clamped_sample(&0), 0.0);
+        assert_eq!(restored.clamped_sample(&1), 1.0);
+        assert_eq!(restored.clamped_sample(&2), 2.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn a_curve_built_through_the_public_api_can_be_serialized() {
+        let mut c = BTreeCurve::<f64>::new_concrete();
+        c.set(0, 0.0, Interpolation::Linear);
+        c.set(1, 1.0, Interpolation::Hold);
+
+        let json = serde_json::to_string(&c).unwrap();
+        let restored: BTreeCurve<f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.clamped_sample(&0), 0.0);
+        assert_eq!(restored.clamped_sample(&1), 1.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn step_interpolation_round_trips_through_its_parametric_json_tag() {
+        let entry: KeyframeEntry<f64> =
+            serde_json::from_str(r#"{"t":1,"interpolation":{"step":0.5},"value":1.0}"#).unwrap();
+
+        assert_eq!(entry.interpolation, Interpolation::Step(0.5));
+        assert_eq!(serde_json::to_string(&entry.interpolation).unwrap(), r#"{"step":0.5}"#);
+    }
+
+    #[test]
+    fn linear_interpolation_works_for_pairs() {
+        let mut c = BTreeCurve::<Pair<f64, f64>>::new();
+        c.set(1, Pair(100.0, 1000.0), Interpolation::Linear);
+        c.set(3, Pair(300.0, 3000.0), Interpolation::Linear);
+
+        assert_eq!(c.value_at(&1), Pair(100.0, 1000.0));
+        assert_eq!(c.value_at(&2), Pair(200.0, 2000.0));
+        assert_eq!(c.value_at(&3), Pair(300.0, 3000.0));
+    }
+
+    #[test]
+    fn slerp_interpolation_works_for_quaternions() {
+        let mut c = BTreeCurve::<Quaternion>::new();
+        let identity = Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+        let quarter_turn = Quaternion { x: 0.0, y: 0.0, z: 1.0, w: 0.0 };
+        c.set(0, identity, Interpolation::Linear);
+        c.set(10, quarter_turn, Interpolation::Linear);
+
+        assert_eq!(c.value_at(&0), identity);
+        assert_eq!(c.value_at(&10), quarter_turn);
+
+        let halfway = c.value_at(&5);
+        let expected = (2.0_f64).sqrt() / 2.0;
+        assert!((halfway.z - expected).abs() < 1e-9);
+        assert!((halfway.w - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn domain_reflects_the_first_and_last_keyframe() {
+        let mut c = BTreeCurve::<f64>::new();
+        assert_eq!(c.domain(), None);
+
+        c.set(1, 100.0, Interpolation::Linear);
+        c.set(6, 600.0, Interpolation::Linear);
+        assert_eq!(c.domain(), Some((1, 6)));
+    }
+
+    #[test]
+    fn sample_iter_walks_the_domain_at_a_fixed_step() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(0, 0.0, Interpolation::Linear);
+        c.set(10, 100.0, Interpolation::Linear);
+
+        let samples: Vec<(Position, f64)> = c.sample_iter(0, 10, 5).collect();
+        assert_eq!(samples, vec![(0, 0.0), (5, 50.0), (10, 100.0)]);
+    }
+
+    #[test]
+    fn sample_iter_yields_nothing_for_an_empty_curve() {
+        let c = BTreeCurve::<f64>::new();
+
+        let samples: Vec<(Position, f64)> = c.sample_iter(0, 5, 1).collect();
+        assert_eq!(samples, vec![]);
+    }
+
+    #[test]
+    fn sample_iter_yields_nothing_for_a_non_positive_step() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(0, 0.0, Interpolation::Linear);
+        c.set(10, 100.0, Interpolation::Linear);
+
+        assert_eq!(c.sample_iter(0, 10, 0).collect::<Vec<_>>(), vec![]);
+        assert_eq!(c.sample_iter(0, 10, -1).collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn resample_bakes_a_spline_into_dense_linear_keyframes() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(0, 0.0, Interpolation::CatmullRom);
+        c.set(10, 1.0, Interpolation::CatmullRom);
+        c.set(20, 2.0, Interpolation::CatmullRom);
+        c.set(30, 3.0, Interpolation::CatmullRom);
+
+        let resampled = c.resample(15);
+        assert_eq!(resampled.domain(), Some((0, 30)));
+        assert_eq!(resampled.value_at(&0), c.value_at(&0));
+        assert_eq!(resampled.value_at(&15), c.value_at(&15));
+        assert_eq!(resampled.value_at(&30), c.value_at(&30));
+    }
+
+    #[test]
+    fn resample_keeps_the_domain_endpoint_when_the_step_does_not_divide_evenly() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(0, 0.0, Interpolation::CatmullRom);
+        c.set(10, 1.0, Interpolation::CatmullRom);
+        c.set(20, 2.0, Interpolation::CatmullRom);
+        c.set(30, 3.0, Interpolation::CatmullRom);
+
+        let resampled = c.resample(7);
+        assert_eq!(resampled.domain(), Some((0, 30)));
+        assert_eq!(resampled.value_at(&30), c.value_at(&30));
+    }
+
+    #[test]
+    fn resample_is_empty_for_a_non_positive_step() {
+        let mut c = BTreeCurve::<f64>::new();
+        c.set(0, 0.0, Interpolation::Linear);
+        c.set(10, 100.0, Interpolation::Linear);
+
+        assert_eq!(c.resample(0).domain(), None);
+    }
+
 }
\ No newline at end of file